@@ -3,21 +3,26 @@
 //! Commands are simple data transfer objects that carry all information needed
 //! to execute a use case. They have no behavior and are validated at the
 //! application layer boundary.
+//!
+//! Market and limit orders are modeled as distinct commands rather than one
+//! struct with an optional price, so a market order can never be constructed
+//! carrying a stray price the customer never agreed to.
 
 // crates/application/src/place_order/command.rs
+use domain::order::{Money, OrderSide};
 
-/// Command to create and persist a new order.
+/// Command to create and persist a new market order.
 ///
-/// This command encapsulates all data required to place an order on behalf
-/// of a customer. It is processed by [`PlaceOrderHandler`].
+/// This command encapsulates all data required to place a market order on
+/// behalf of a customer. It is processed by [`PlaceOrderHandler`].
 ///
 /// # Example
 ///
 /// ```rust
-/// let command = PlaceOrderCommand {
+/// let command = PlaceMarketOrderCommand {
 ///     customer_id: "cust-123".to_string(),
 ///     items: vec![
-///         PlaceOrderItem {
+///         PlaceMarketOrderItem {
 ///             product_id: "prod-456".to_string(),
 ///             quantity: 2,
 ///         },
@@ -25,20 +30,65 @@
 /// };
 /// ```
 #[derive(Debug, Clone)]
-pub struct PlaceOrderCommand {
+pub struct PlaceMarketOrderCommand {
     /// The unique identifier of the customer placing the order.
     pub customer_id: String,
 
     /// The items to include in the order.
-    pub items: Vec<PlaceOrderItem>,
+    pub items: Vec<PlaceMarketOrderItem>,
 }
 
-/// Data for a single line item in a place order command.
+/// Data for a single line item in a place market order command.
 #[derive(Debug, Clone)]
-pub struct PlaceOrderItem {
+pub struct PlaceMarketOrderItem {
     /// The unique identifier of the product to order.
     pub product_id: String,
 
     /// The number of units to order. Must be positive.
     pub quantity: u32,
 }
+
+/// Command to create and persist a new limit order.
+///
+/// This command encapsulates all data required to place a limit order on
+/// behalf of a customer. It is processed by [`PlaceOrderHandler`].
+///
+/// # Example
+///
+/// ```rust
+/// let command = PlaceLimitOrderCommand {
+///     customer_id: "cust-123".to_string(),
+///     items: vec![
+///         PlaceLimitOrderItem {
+///             product_id: "prod-456".to_string(),
+///             quantity: 2,
+///             side: OrderSide::Buy,
+///             limit_price: Money::new(2999, "USD")?,
+///         },
+///     ],
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlaceLimitOrderCommand {
+    /// The unique identifier of the customer placing the order.
+    pub customer_id: String,
+
+    /// The items to include in the order.
+    pub items: Vec<PlaceLimitOrderItem>,
+}
+
+/// Data for a single line item in a place limit order command.
+#[derive(Debug, Clone)]
+pub struct PlaceLimitOrderItem {
+    /// The unique identifier of the product to order.
+    pub product_id: String,
+
+    /// The number of units to order. Must be positive.
+    pub quantity: u32,
+
+    /// Which side of the trade this limit applies to.
+    pub side: OrderSide,
+
+    /// The maximum (buy) or minimum (sell) acceptable price per unit.
+    pub limit_price: Money,
+}
@@ -0,0 +1,84 @@
+//! Use case handler for reaping expired orders.
+//!
+//! This module implements the application service that cancels
+//! confirmed-but-unshipped orders once their expiry has passed, on behalf
+//! of the system rather than the customer.
+
+// crates/application/src/expired_order/handler.rs
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use domain::order::{OrderRepository, OrderReason, OrderStatus};
+use thiserror::Error;
+
+/// Application service that cancels expired orders.
+///
+/// This handler is typically invoked on a schedule. It is idempotent: an
+/// order that is already `Cancelled` by the time it is processed is simply
+/// skipped rather than re-cancelled.
+///
+/// # Example
+///
+/// ```rust
+/// let handler = ExpiredOrderHandler::new(Arc::new(postgres_order_repo));
+///
+/// let cancelled = handler.handle(Utc::now()).await?;
+/// ```
+pub struct ExpiredOrderHandler {
+    order_repo: Arc<dyn OrderRepository>,
+}
+
+impl ExpiredOrderHandler {
+    /// Creates a new ExpiredOrderHandler with the required dependencies.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_repo` - Repository for loading and persisting Order aggregates.
+    pub fn new(order_repo: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repo }
+    }
+
+    /// Executes the expiry reaper use case.
+    ///
+    /// Loads confirmed-but-unshipped orders whose expiry has passed,
+    /// cancels each with reason `Expired`, and persists the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The instant to evaluate expiry against.
+    ///
+    /// # Returns
+    ///
+    /// The number of orders that were cancelled.
+    ///
+    /// # Errors
+    ///
+    /// * [`ExpiredOrderError::Repository`] - If loading or persisting fails.
+    pub async fn handle(&self, now: DateTime<Utc>) -> Result<usize, ExpiredOrderError> {
+        let expired = self.order_repo.find_expired(now).await?;
+
+        let mut cancelled = 0;
+        for mut order in expired {
+            if *order.status() == OrderStatus::Cancelled {
+                continue;
+            }
+
+            order.cancel(OrderReason::Expired)?;
+            self.order_repo.save(&order).await?;
+            cancelled += 1;
+        }
+
+        Ok(cancelled)
+    }
+}
+
+/// Errors that can occur when executing the expiry reaper use case.
+#[derive(Debug, Error)]
+pub enum ExpiredOrderError {
+    /// A domain rule was violated while cancelling an order.
+    #[error("Order error: {0}")]
+    Order(#[from] domain::order::OrderError),
+
+    /// A database operation failed.
+    #[error("Repository error: {0}")]
+    Repository(#[from] domain::order::RepositoryError),
+}
@@ -6,9 +6,9 @@
 
 // crates/application/src/place_order/handler.rs
 use std::sync::Arc;
-use domain::order::{Order, OrderRepository, CustomerId, ProductId};
+use domain::order::{Order, OrderKind, OrderRepository, OrderSide, CustomerId, ProductId};
 use crate::ports::ProductRepository;
-use super::command::PlaceOrderCommand;
+use super::command::{PlaceLimitOrderCommand, PlaceMarketOrderCommand};
 use thiserror::Error;
 
 /// Application service that handles the place order use case.
@@ -26,15 +26,15 @@ use thiserror::Error;
 ///     Arc::new(postgres_product_repo),
 /// );
 ///
-/// let command = PlaceOrderCommand {
+/// let command = PlaceMarketOrderCommand {
 ///     customer_id: "cust-123".to_string(),
-///     items: vec![PlaceOrderItem {
+///     items: vec![PlaceMarketOrderItem {
 ///         product_id: "prod-456".to_string(),
 ///         quantity: 2,
 ///     }],
 /// };
 ///
-/// let order_id = handler.handle(command).await?;
+/// let order_id = handler.handle_market(command).await?;
 /// ```
 pub struct PlaceOrderHandler {
     order_repo: Arc<dyn OrderRepository>,
@@ -55,14 +55,15 @@ impl PlaceOrderHandler {
         Self { order_repo, product_repo }
     }
 
-    /// Executes the place order use case.
+    /// Executes the place market order use case.
     ///
-    /// Creates a new order with the specified items, validates products exist,
-    /// persists the order, and returns the new order's ID.
+    /// Creates a new order with the specified items, pulling the live product
+    /// price for each, validates products exist, persists the order, and
+    /// returns the new order's ID.
     ///
     /// # Arguments
     ///
-    /// * `cmd` - The place order command containing customer and item data.
+    /// * `cmd` - The place market order command containing customer and item data.
     ///
     /// # Returns
     ///
@@ -75,7 +76,7 @@ impl PlaceOrderHandler {
     /// * [`PlaceOrderError::ProductNotFound`] - If any product does not exist.
     /// * [`PlaceOrderError::Order`] - If domain rules are violated.
     /// * [`PlaceOrderError::Repository`] - If persistence fails.
-    pub async fn handle(&self, cmd: PlaceOrderCommand) -> Result<String, PlaceOrderError> {
+    pub async fn handle_market(&self, cmd: PlaceMarketOrderCommand) -> Result<String, PlaceOrderError> {
         let customer_id = CustomerId::from_string(&cmd.customer_id)
             .map_err(|_| PlaceOrderError::InvalidCustomerId)?;
 
@@ -90,7 +91,71 @@ impl PlaceOrderHandler {
             let product_id = ProductId::from_string(&item.product_id)
                 .map_err(|_| PlaceOrderError::InvalidProductId)?;
 
-            order.add_item(product_id, item.quantity, product.price)?;
+            order.add_item(product_id, item.quantity, product.price, OrderKind::Market)?;
+        }
+
+        self.order_repo.save(&order).await?;
+
+        Ok(order.id().as_str())
+    }
+
+    /// Executes the place limit order use case.
+    ///
+    /// Creates a new order with the specified items, rejecting any item whose
+    /// live product price does not respect the customer's limit, persists
+    /// the order, and returns the new order's ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The place limit order command containing customer and item data.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the newly created order as a string.
+    ///
+    /// # Errors
+    ///
+    /// * [`PlaceOrderError::InvalidCustomerId`] - If customer ID format is invalid.
+    /// * [`PlaceOrderError::InvalidProductId`] - If product ID format is invalid.
+    /// * [`PlaceOrderError::ProductNotFound`] - If any product does not exist.
+    /// * [`PlaceOrderError::CurrencyMismatch`] - If the limit price's currency
+    ///   does not match the product's price currency.
+    /// * [`PlaceOrderError::PriceOutsideLimit`] - If the product's price violates the limit.
+    /// * [`PlaceOrderError::Order`] - If domain rules are violated.
+    /// * [`PlaceOrderError::Repository`] - If persistence fails.
+    pub async fn handle_limit(&self, cmd: PlaceLimitOrderCommand) -> Result<String, PlaceOrderError> {
+        let customer_id = CustomerId::from_string(&cmd.customer_id)
+            .map_err(|_| PlaceOrderError::InvalidCustomerId)?;
+
+        let mut order = Order::create(customer_id);
+
+        for item in cmd.items {
+            let product = self.product_repo
+                .find_by_id(&item.product_id)
+                .await?
+                .ok_or(PlaceOrderError::ProductNotFound(item.product_id.clone()))?;
+
+            if product.price.currency() != item.limit_price.currency() {
+                return Err(PlaceOrderError::CurrencyMismatch);
+            }
+
+            let outside_limit = match item.side {
+                OrderSide::Buy => product.price.amount() > item.limit_price.amount(),
+                OrderSide::Sell => product.price.amount() < item.limit_price.amount(),
+            };
+            if outside_limit {
+                return Err(PlaceOrderError::PriceOutsideLimit);
+            }
+
+            let product_id = ProductId::from_string(&item.product_id)
+                .map_err(|_| PlaceOrderError::InvalidProductId)?;
+
+            order.add_item(
+                product_id,
+                item.quantity,
+                product.price,
+                OrderKind::Limit { side: item.side, limit_price: item.limit_price },
+            )?;
         }
 
         self.order_repo.save(&order).await?;
@@ -114,6 +179,15 @@ pub enum PlaceOrderError {
     #[error("Product not found: {0}")]
     ProductNotFound(String),
 
+    /// A limit order's product price does not respect the customer's limit.
+    #[error("Product price is outside the customer's limit")]
+    PriceOutsideLimit,
+
+    /// A limit order's limit price is quoted in a different currency than
+    /// the product's price.
+    #[error("Limit price currency does not match the product's price currency")]
+    CurrencyMismatch,
+
     /// A domain rule was violated during order creation.
     #[error("Order error: {0}")]
     Order(#[from] domain::order::OrderError),
@@ -0,0 +1,126 @@
+//! Use case handler for running the matching engine against resting limit
+//! orders and applying the resulting fills to their aggregates.
+
+// crates/application/src/matching/handler.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+use domain::matching::{Match, OrderBook, OrderRef, Side};
+use domain::order::{OrderRepository, OrderId};
+use thiserror::Error;
+
+/// Application service that drives an [`OrderBook`] and persists the fills
+/// it produces.
+///
+/// This handler owns translating [`Match`]es, which are expressed purely in
+/// terms of order book quantities and prices, into calls against the
+/// [`Order`](domain::order::Order) aggregates they belong to.
+///
+/// # Example
+///
+/// ```rust
+/// let handler = MatchOrdersHandler::new(Arc::new(postgres_order_repo));
+///
+/// let matches = handler.submit(&mut book, Side::Buy, incoming).await?;
+/// ```
+pub struct MatchOrdersHandler {
+    order_repo: Arc<dyn OrderRepository>,
+}
+
+impl MatchOrdersHandler {
+    /// Creates a new MatchOrdersHandler with the required dependencies.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_repo` - Repository for loading and persisting Order aggregates.
+    pub fn new(order_repo: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repo }
+    }
+
+    /// Submits an incoming limit order to the given book, then applies every
+    /// resulting match to the buy and sell order aggregates.
+    ///
+    /// # Arguments
+    ///
+    /// * `book` - The order book to match against; mutated in place.
+    /// * `side` - Which side the incoming order rests on.
+    /// * `incoming` - The incoming limit order.
+    ///
+    /// # Returns
+    ///
+    /// The matches produced by this submission.
+    ///
+    /// # Errors
+    ///
+    /// * [`MatchOrdersError::Money`] - If `incoming` is quoted in a different
+    ///   currency than the book has already established.
+    /// * [`MatchOrdersError::Repository`] - If loading or persisting an order fails.
+    /// * [`MatchOrdersError::Order`] - If applying a fill violates a domain rule.
+    pub async fn submit(
+        &self,
+        book: &mut OrderBook,
+        side: Side,
+        incoming: OrderRef,
+    ) -> Result<Vec<Match>, MatchOrdersError> {
+        let matches = book.submit(side, incoming)?;
+
+        // An order can appear in more than one match (e.g. a large incoming
+        // order sweeping several resting counterparties). Only the last
+        // match touching a given order is the one that's final for this
+        // submission; earlier ones are intermediate and shouldn't trip the
+        // order's partially-fillable invariant.
+        let mut last_match_index: HashMap<&OrderId, usize> = HashMap::new();
+        for (i, m) in matches.iter().enumerate() {
+            last_match_index.insert(&m.buy_order_id, i);
+            last_match_index.insert(&m.sell_order_id, i);
+        }
+
+        for (i, m) in matches.iter().enumerate() {
+            let buy_is_final = last_match_index[&m.buy_order_id] == i;
+            let sell_is_final = last_match_index[&m.sell_order_id] == i;
+            self.apply_fill(&m.buy_order_id, book.product_id().clone(), m.quantity, m.price.clone(), buy_is_final).await?;
+            self.apply_fill(&m.sell_order_id, book.product_id().clone(), m.quantity, m.price.clone(), sell_is_final).await?;
+        }
+
+        Ok(matches)
+    }
+
+    async fn apply_fill(
+        &self,
+        order_id: &OrderId,
+        product_id: domain::order::ProductId,
+        quantity: u32,
+        price: domain::order::Money,
+        final_execution: bool,
+    ) -> Result<(), MatchOrdersError> {
+        let mut order = self.order_repo
+            .find_by_id(order_id)
+            .await?
+            .ok_or(MatchOrdersError::OrderNotFound)?;
+
+        order.fill_item(&product_id, quantity, price, final_execution)?;
+        self.order_repo.save(&order).await?;
+
+        Ok(())
+    }
+}
+
+/// Errors that can occur when executing the matching use case.
+#[derive(Debug, Error)]
+pub enum MatchOrdersError {
+    /// A matched order could not be found for applying its fill.
+    #[error("Matched order not found")]
+    OrderNotFound,
+
+    /// A domain rule was violated while applying a fill.
+    #[error("Order error: {0}")]
+    Order(#[from] domain::order::OrderError),
+
+    /// The incoming order's currency did not match the book's established
+    /// currency.
+    #[error("Money error: {0}")]
+    Money(#[from] domain::order::MoneyError),
+
+    /// A database operation failed.
+    #[error("Repository error: {0}")]
+    Repository(#[from] domain::order::RepositoryError),
+}
@@ -0,0 +1,176 @@
+//! Read service that assembles the set of orders still actionable by
+//! fulfillment and settlement.
+
+// crates/application/src/solvable_orders/handler.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use domain::order::{FulfillmentStatus, Money, Order, OrderId, OrderRepository, Rate};
+use domain::shared::Entity;
+use thiserror::Error;
+
+/// The set of orders still actionable by fulfillment/settlement, along with
+/// their combined value.
+///
+/// An order is solvable if it is not expired, not already fully fulfilled,
+/// and carries no recorded placement error.
+#[derive(Debug)]
+pub struct SolvableOrders {
+    /// The solvable orders.
+    pub orders: Vec<Order>,
+    /// The combined total value of `orders`, in the settlement currency of
+    /// the first order in `orders` (`"USD"` when `orders` is empty).
+    pub total_value: Money,
+}
+
+impl SolvableOrders {
+    /// Merges this set with another by order id, with `other`'s entry
+    /// winning on a conflict, then re-applies the solvability predicate
+    /// against `now` so a caller can fold newly-loaded orders into a
+    /// previously computed set without recomputing it from scratch.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The set to merge in; its entries win on id conflicts.
+    /// * `now` - The instant to re-evaluate expiry against.
+    /// * `rates` - Exchange rates needed to recompute `total_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolvableOrdersError::Order`] if an order's total cannot be
+    /// computed (e.g. a missing conversion rate).
+    pub fn combine_with(
+        self,
+        other: SolvableOrders,
+        now: DateTime<Utc>,
+        rates: &HashMap<String, Rate>,
+    ) -> Result<SolvableOrders, SolvableOrdersError> {
+        let mut merged: Vec<Order> = Vec::with_capacity(self.orders.len() + other.orders.len());
+        let mut index_by_id: HashMap<OrderId, usize> = HashMap::new();
+
+        for order in self.orders.into_iter().chain(other.orders) {
+            match index_by_id.get(order.id()) {
+                Some(&index) => merged[index] = order,
+                None => {
+                    index_by_id.insert(order.id().clone(), merged.len());
+                    merged.push(order);
+                }
+            }
+        }
+
+        let orders: Vec<Order> = merged.into_iter()
+            .filter(|order| is_solvable(order, now))
+            .collect();
+        let total_value = total_of(&orders, rates)?;
+
+        Ok(SolvableOrders { orders, total_value })
+    }
+}
+
+/// Application service that loads the current set of solvable orders.
+///
+/// # Example
+///
+/// ```rust
+/// let handler = SolvableOrdersHandler::new(Arc::new(postgres_order_repo));
+///
+/// let solvable = handler.handle(Utc::now(), &rates).await?;
+/// ```
+pub struct SolvableOrdersHandler {
+    order_repo: Arc<dyn OrderRepository>,
+}
+
+impl SolvableOrdersHandler {
+    /// Creates a new SolvableOrdersHandler with the required dependencies.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_repo` - Repository for loading candidate Order aggregates.
+    pub fn new(order_repo: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repo }
+    }
+
+    /// Executes the solvable orders query.
+    ///
+    /// Loads candidate orders and retains only those that are not expired,
+    /// not already fully fulfilled, and carry no recorded placement error.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The instant to evaluate expiry against.
+    /// * `rates` - Exchange rates needed to compute the combined total value.
+    ///
+    /// # Errors
+    ///
+    /// * [`SolvableOrdersError::Order`] - If an order's total cannot be computed.
+    /// * [`SolvableOrdersError::Repository`] - If loading candidates fails.
+    pub async fn handle(
+        &self,
+        now: DateTime<Utc>,
+        rates: &HashMap<String, Rate>,
+    ) -> Result<SolvableOrders, SolvableOrdersError> {
+        let candidates = self.order_repo.find_candidates().await?;
+
+        let orders: Vec<Order> = candidates.into_iter()
+            .filter(|order| is_solvable(order, now))
+            .collect();
+        let total_value = total_of(&orders, rates)?;
+
+        Ok(SolvableOrders { orders, total_value })
+    }
+}
+
+/// Whether an order is still actionable by fulfillment/settlement.
+fn is_solvable(order: &Order, now: DateTime<Utc>) -> bool {
+    let not_expired = order.expiry_timestamp().map(|expiry| expiry >= now).unwrap_or(true);
+    let not_fulfilled = order.fulfillment_status() != FulfillmentStatus::FullyFilled;
+    let no_placement_error = order.placement_error().is_none();
+
+    not_expired && not_fulfilled && no_placement_error
+}
+
+/// Sums each order's total, converting into the first order's settlement
+/// currency so orders settled in different currencies don't produce a
+/// currency-mismatch error.
+///
+/// "First" here means first in `orders`'s order, which callers must keep
+/// deterministic (see [`SolvableOrders::combine_with`]) so the settlement
+/// currency of a combined total doesn't vary between runs.
+fn total_of(orders: &[Order], rates: &HashMap<String, Rate>) -> Result<Money, SolvableOrdersError> {
+    let Some(first) = orders.first() else {
+        return Ok(Money::zero("USD"));
+    };
+    let settlement_currency = first.settlement_currency();
+
+    let mut total = Money::zero(settlement_currency);
+    for order in orders {
+        let order_total = order.total(rates)?;
+
+        let converted = if order_total.currency() == settlement_currency {
+            order_total
+        } else {
+            let rate = rates.get(order_total.currency())
+                .ok_or(domain::order::MoneyError::ConversionUnavailable)?;
+            order_total.convert(settlement_currency, rate)?
+        };
+
+        total = total.add(&converted)?;
+    }
+    Ok(total)
+}
+
+/// Errors that can occur when executing the solvable orders query.
+#[derive(Debug, Error)]
+pub enum SolvableOrdersError {
+    /// A domain rule was violated while computing an order's total.
+    #[error("Order error: {0}")]
+    Order(#[from] domain::order::OrderError),
+
+    /// A monetary operation failed while combining totals.
+    #[error("Money error: {0}")]
+    Money(#[from] domain::order::MoneyError),
+
+    /// A database operation failed.
+    #[error("Repository error: {0}")]
+    Repository(#[from] domain::order::RepositoryError),
+}
@@ -0,0 +1,52 @@
+//! Domain events emitted by the Order aggregate.
+//!
+//! Events represent significant, already-happened state changes. They are
+//! collected on the aggregate and published after a successful persistence
+//! operation, enabling event-driven integration with other bounded contexts.
+
+// crates/domain/src/order/events.rs
+use super::value_objects::{CustomerId, Money, OrderId, OrderReason, ProductId};
+
+/// Domain events raised by the [`Order`](super::aggregate::Order) aggregate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderEvent {
+    /// Raised when a new order is created, either by a customer or by the
+    /// system itself (see [`OrderReason`]).
+    Created {
+        /// The identifier of the newly created order.
+        order_id: OrderId,
+        /// The customer the order was placed for.
+        customer_id: CustomerId,
+        /// Why the order was created.
+        reason: OrderReason,
+    },
+
+    /// Raised when an order transitions from Draft to Confirmed.
+    Confirmed {
+        /// The identifier of the confirmed order.
+        order_id: OrderId,
+        /// The total amount due at confirmation time.
+        total: Money,
+    },
+
+    /// Raised when an order is cancelled, whether by the customer or by a
+    /// system process such as the expiry reaper.
+    Cancelled {
+        /// The identifier of the cancelled order.
+        order_id: OrderId,
+        /// Why the order was cancelled.
+        reason: OrderReason,
+    },
+
+    /// Raised when a line item within an order is incrementally executed.
+    Filled {
+        /// The identifier of the order the executed item belongs to.
+        order_id: OrderId,
+        /// The product whose line item was executed.
+        product_id: ProductId,
+        /// The quantity executed in this increment.
+        executed: u32,
+        /// The price this increment executed at.
+        executed_price: Money,
+    },
+}
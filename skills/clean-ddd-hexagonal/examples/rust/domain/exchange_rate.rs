@@ -0,0 +1,45 @@
+//! Driven port for currency exchange rate lookups.
+//!
+//! This module defines the abstract port the domain requires to resolve
+//! exchange rates between currencies. Concrete implementations (a pricing
+//! service client, a cached feed, etc.) reside in the infrastructure layer.
+
+// crates/domain/src/exchange_rate.rs
+use async_trait::async_trait;
+use crate::order::{Rate, RepositoryError};
+
+/// Driven port (secondary port) for looking up currency exchange rates.
+///
+/// Like [`OrderRepository`](crate::order::OrderRepository), this trait
+/// represents a dependency the domain and application layers need
+/// satisfied by infrastructure, without depending on how it's satisfied.
+///
+/// # Example
+///
+/// ```rust
+/// use domain::exchange_rate::ExchangeRateProvider;
+///
+/// struct LiveExchangeRateProvider { /* ... */ }
+///
+/// #[async_trait]
+/// impl ExchangeRateProvider for LiveExchangeRateProvider {
+///     async fn rate(&self, from: &str, to: &str) -> Result<Rate, RepositoryError> {
+///         // Implementation
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait ExchangeRateProvider: Send + Sync {
+    /// Retrieves the current exchange rate from one currency to another.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - ISO 4217 currency code to convert from.
+    /// * `to` - ISO 4217 currency code to convert into.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::NotFound`] if no rate is published for the
+    /// pair, or [`RepositoryError::Database`] if the lookup fails.
+    async fn rate(&self, from: &str, to: &str) -> Result<Rate, RepositoryError>;
+}
@@ -0,0 +1,186 @@
+//! Price-time-priority matching engine over per-product order books.
+//!
+//! This module is deliberately independent of the [`Order`](crate::order::Order)
+//! aggregate: it works against lightweight [`OrderRef`]s so the book can be
+//! kept in memory and matched at high frequency, leaving the application
+//! layer to translate [`Match`]es back into aggregate fills.
+
+// crates/domain/src/matching/mod.rs
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, VecDeque};
+use crate::order::{Money, MoneyError, OrderId, ProductId};
+
+/// Which side of the book an order rests on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// A buy order, matched against the ask side.
+    Buy,
+    /// A sell order, matched against the bid side.
+    Sell,
+}
+
+/// A lightweight reference to a resting or incoming limit order, as seen by
+/// the matching engine. This intentionally carries only what matching needs,
+/// not the full [`Order`](crate::order::Order) aggregate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRef {
+    /// The identifier of the order this reference represents.
+    pub order_id: OrderId,
+    /// The quantity still awaiting execution.
+    pub quantity: u32,
+    /// The limit price this order rests at.
+    pub limit_price: Money,
+}
+
+/// A single execution produced by matching a buy order against a sell order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// The identifier of the buy-side order.
+    pub buy_order_id: OrderId,
+    /// The identifier of the sell-side order.
+    pub sell_order_id: OrderId,
+    /// The quantity executed by this match.
+    pub quantity: u32,
+    /// The execution price, taken from the resting order for price-time priority.
+    pub price: Money,
+}
+
+/// An in-memory, price-time-priority order book for a single product.
+///
+/// Bids are kept in a `BTreeMap` keyed descending by limit price (best bid
+/// first); asks ascending (best ask first). Within a price level, orders are
+/// matched FIFO by insertion order.
+pub struct OrderBook {
+    product_id: ProductId,
+    currency: Option<String>,
+    bids: BTreeMap<Reverse<Money>, VecDeque<OrderRef>>,
+    asks: BTreeMap<Money, VecDeque<OrderRef>>,
+}
+
+impl OrderBook {
+    /// Creates an empty order book for the given product.
+    ///
+    /// The book has no settlement currency until the first order is
+    /// submitted; every order after that must be quoted in that same
+    /// currency.
+    pub fn new(product_id: ProductId) -> Self {
+        Self {
+            product_id,
+            currency: None,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the product this book matches orders for.
+    pub fn product_id(&self) -> &ProductId { &self.product_id }
+
+    /// Submits an incoming limit order, matching it against the opposite
+    /// side of the book while prices cross.
+    ///
+    /// Fully filled resting orders are removed from the book. Any quantity
+    /// left unmatched on the incoming order is inserted as a new resting
+    /// level.
+    ///
+    /// # Returns
+    ///
+    /// The matches produced by this submission, in the order they occurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if `incoming` is quoted in a
+    /// different currency than the book has already established. This is
+    /// checked up front, before any matching happens, so a rejected order
+    /// never partially matches.
+    pub fn submit(&mut self, side: Side, mut incoming: OrderRef) -> Result<Vec<Match>, MoneyError> {
+        match &self.currency {
+            Some(currency) if currency != incoming.limit_price.currency() => {
+                return Err(MoneyError::CurrencyMismatch);
+            }
+            Some(_) => {}
+            None => self.currency = Some(incoming.limit_price.currency().to_string()),
+        }
+
+        let mut matches = Vec::new();
+
+        while incoming.quantity > 0 {
+            let resting = match side {
+                Side::Buy => Self::best_crossing(&mut self.asks, &incoming.limit_price, |ask| incoming.limit_price >= *ask),
+                Side::Sell => Self::best_crossing_rev(&mut self.bids, &incoming.limit_price, |bid| incoming.limit_price <= *bid),
+            };
+
+            let Some(mut resting) = resting else { break };
+
+            let executed = incoming.quantity.min(resting.quantity);
+            let price = resting.limit_price.clone();
+
+            matches.push(match side {
+                Side::Buy => Match {
+                    buy_order_id: incoming.order_id.clone(),
+                    sell_order_id: resting.order_id.clone(),
+                    quantity: executed,
+                    price,
+                },
+                Side::Sell => Match {
+                    buy_order_id: resting.order_id.clone(),
+                    sell_order_id: incoming.order_id.clone(),
+                    quantity: executed,
+                    price,
+                },
+            });
+
+            incoming.quantity -= executed;
+            resting.quantity -= executed;
+
+            if resting.quantity > 0 {
+                // Still has quantity left: keeps its place at the front of
+                // its price level (price-time priority). The level may have
+                // been removed when this order was popped as its only entry.
+                match side {
+                    Side::Buy => self.asks.entry(resting.limit_price.clone()).or_default().push_front(resting),
+                    Side::Sell => self.bids.entry(Reverse(resting.limit_price.clone())).or_default().push_front(resting),
+                }
+            }
+        }
+
+        if incoming.quantity > 0 {
+            match side {
+                Side::Buy => self.bids.entry(Reverse(incoming.limit_price.clone())).or_default().push_back(incoming),
+                Side::Sell => self.asks.entry(incoming.limit_price.clone()).or_default().push_back(incoming),
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Pops the front order of the best ask level if `crosses` holds for it,
+    /// removing the level entirely once it empties.
+    fn best_crossing(
+        asks: &mut BTreeMap<Money, VecDeque<OrderRef>>,
+        _incoming_price: &Money,
+        crosses: impl Fn(&Money) -> bool,
+    ) -> Option<OrderRef> {
+        let best_price = asks.keys().next().filter(|price| crosses(price))?.clone();
+        let level = asks.get_mut(&best_price)?;
+        let resting = level.pop_front();
+        if level.is_empty() {
+            asks.remove(&best_price);
+        }
+        resting
+    }
+
+    /// Same as [`OrderBook::best_crossing`] but for the descending-keyed bid side.
+    fn best_crossing_rev(
+        bids: &mut BTreeMap<Reverse<Money>, VecDeque<OrderRef>>,
+        _incoming_price: &Money,
+        crosses: impl Fn(&Money) -> bool,
+    ) -> Option<OrderRef> {
+        let best_price = bids.keys().next().filter(|Reverse(price)| crosses(price))?.0.clone();
+        let level = bids.get_mut(&Reverse(best_price.clone()))?;
+        let resting = level.pop_front();
+        if level.is_empty() {
+            bids.remove(&Reverse(best_price));
+        }
+        resting
+    }
+}
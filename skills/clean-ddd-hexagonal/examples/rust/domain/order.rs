@@ -5,15 +5,19 @@
 //! item management, and state transitions.
 
 // crates/domain/src/order/aggregate.rs
+use std::collections::HashMap;
 use crate::shared::{AggregateRoot, Entity};
 use super::value_objects::*;
 use super::events::*;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 /// Lifecycle states for an Order.
 ///
 /// Orders progress through states: `Draft` -> `Confirmed` -> `Shipped`.
 /// `Cancelled` is a terminal state reachable from `Draft` or `Confirmed`.
+/// `Fulfilled` is a terminal state reached automatically once every line
+/// item has been fully executed (see [`Order::fill_item`]).
 #[derive(Debug, Clone, PartialEq)]
 pub enum OrderStatus {
     /// Order is being created, can be modified freely.
@@ -22,10 +26,24 @@ pub enum OrderStatus {
     Confirmed,
     /// Order has been dispatched to carrier.
     Shipped,
+    /// Every line item has been fully executed.
+    Fulfilled,
     /// Order has been cancelled.
     Cancelled,
 }
 
+/// Derived fulfillment progress for an [`Order`], computed from the executed
+/// quantity of each line item rather than stored directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FulfillmentStatus {
+    /// No line item has been executed at all.
+    Unfilled,
+    /// At least one unit has executed, but not every line item is complete.
+    PartiallyFilled,
+    /// Every line item has executed its full ordered quantity.
+    FullyFilled,
+}
+
 /// Aggregate root representing a customer's purchase order.
 ///
 /// The Order aggregate maintains the consistency boundary for all order-related
@@ -40,16 +58,16 @@ pub enum OrderStatus {
 /// # Example
 ///
 /// ```rust
-/// use domain::order::{Order, CustomerId, ProductId, Money};
+/// use domain::order::{Order, CustomerId, ProductId, Money, OrderKind};
 ///
 /// let customer_id = CustomerId::from_string("cust-123")?;
 /// let mut order = Order::create(customer_id);
 ///
 /// let product_id = ProductId::from_string("prod-456")?;
 /// let price = Money::new(2999, "USD")?;
-/// order.add_item(product_id, 2, price)?;
+/// order.add_item(product_id, 2, price, OrderKind::Market)?;
 ///
-/// order.confirm()?;
+/// order.confirm(&HashMap::new())?;
 /// ```
 #[derive(Debug)]
 pub struct Order {
@@ -57,14 +75,20 @@ pub struct Order {
     customer_id: CustomerId,
     items: Vec<OrderItem>,
     status: OrderStatus,
+    reason: OrderReason,
+    expiry_timestamp: Option<DateTime<Utc>>,
+    partially_fillable: bool,
+    settlement_currency: String,
+    placement_error: Option<String>,
     events: Vec<OrderEvent>,
 }
 
 impl Order {
-    /// Creates a new Order in Draft status.
+    /// Creates a new Order in Draft status, placed directly by a customer.
     ///
-    /// This factory method is the only way to create new orders, ensuring
-    /// the `OrderEvent::Created` event is always emitted.
+    /// This factory method is the usual way to create new orders, ensuring
+    /// the `OrderEvent::Created` event is always emitted. For orders the
+    /// system generates itself, see [`Order::create_with_reason`].
     ///
     /// # Arguments
     ///
@@ -74,21 +98,201 @@ impl Order {
     ///
     /// A new `Order` instance in `Draft` status.
     pub fn create(customer_id: CustomerId) -> Self {
+        Self::create_with_reason(customer_id, OrderReason::Manual)
+    }
+
+    /// Creates a new Order in Draft status for the given [`OrderReason`].
+    ///
+    /// Use this when the system itself is generating the order (e.g. a
+    /// stop-loss or take-profit order) rather than the customer placing it
+    /// directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer_id` - The identifier of the customer the order is for.
+    /// * `reason` - Why the order is being created.
+    ///
+    /// # Returns
+    ///
+    /// A new `Order` instance in `Draft` status.
+    pub fn create_with_reason(customer_id: CustomerId, reason: OrderReason) -> Self {
         let id = OrderId::new();
         let mut order = Self {
             id: id.clone(),
             customer_id: customer_id.clone(),
             items: Vec::new(),
             status: OrderStatus::Draft,
+            reason,
+            expiry_timestamp: None,
+            partially_fillable: false,
+            settlement_currency: "USD".to_string(),
+            placement_error: None,
             events: Vec::new(),
         };
         order.events.push(OrderEvent::Created {
             order_id: id,
             customer_id,
+            reason,
         });
         order
     }
 
+    /// Sets the timestamp after which this order should be treated as
+    /// expired by the expiry reaper.
+    ///
+    /// # Arguments
+    ///
+    /// * `expiry_timestamp` - The instant the order expires.
+    pub fn set_expiry_timestamp(&mut self, expiry_timestamp: DateTime<Utc>) {
+        self.expiry_timestamp = Some(expiry_timestamp);
+    }
+
+    /// Returns the timestamp after which this order is considered expired,
+    /// if one was set.
+    pub fn expiry_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.expiry_timestamp
+    }
+
+    /// Returns why this order was created.
+    pub fn reason(&self) -> OrderReason { self.reason }
+
+    /// Marks whether this order may be filled in multiple increments rather
+    /// than all at once.
+    pub fn set_partially_fillable(&mut self, partially_fillable: bool) {
+        self.partially_fillable = partially_fillable;
+    }
+
+    /// Returns whether this order may be filled in multiple increments.
+    pub fn partially_fillable(&self) -> bool { self.partially_fillable }
+
+    /// Sets the currency the order's total is settled in.
+    ///
+    /// Defaults to `"USD"`. Line items priced in other currencies are
+    /// converted into this currency by [`Order::total`].
+    pub fn set_settlement_currency(&mut self, currency: &str) {
+        self.settlement_currency = currency.to_uppercase();
+    }
+
+    /// Returns the currency the order's total is settled in.
+    pub fn settlement_currency(&self) -> &str { &self.settlement_currency }
+
+    /// Records that placing this order failed for the given reason,
+    /// excluding it from the solvable orders query.
+    pub fn set_placement_error(&mut self, error: String) {
+        self.placement_error = Some(error);
+    }
+
+    /// Returns the recorded placement error, if any.
+    pub fn placement_error(&self) -> Option<&String> { self.placement_error.as_ref() }
+
+    /// Records an incremental execution against one of this order's line
+    /// items.
+    ///
+    /// Only orders that have progressed past `Draft` may be filled; a
+    /// `Draft` order was never confirmed and a `Cancelled` one must not be
+    /// modified. Auto-transitions the order to the terminal `Fulfilled`
+    /// status once every line item has fully executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_id` - The product whose line item was executed.
+    /// * `executed` - The quantity executed in this increment.
+    /// * `executed_price` - The price this increment executed at.
+    /// * `final_execution` - Whether the caller expects no further
+    ///   executions against this order after this call (e.g. this is the
+    ///   last match a matching round produced for it). A non-partially-fillable
+    ///   order can still be filled across several calls, as long as each
+    ///   intermediate call passes `false`; only a call that passes `true`
+    ///   is held to fully completing the order.
+    ///
+    /// # Errors
+    ///
+    /// * [`OrderError::InvalidState`] - If the order is not `Confirmed` or `Shipped`.
+    /// * [`OrderError::ItemNotFound`] - If no line item matches `product_id`.
+    /// * [`OrderError::OrderOverfill`] - If `executed` exceeds the item's remaining quantity.
+    /// * [`OrderError::PartialFillNotAllowed`] - If the order isn't [`Order::partially_fillable`],
+    ///   `final_execution` is `true`, and this execution wouldn't fully fill the order.
+    pub fn fill_item(
+        &mut self,
+        product_id: &ProductId,
+        executed: u32,
+        executed_price: Money,
+        final_execution: bool,
+    ) -> Result<(), OrderError> {
+        if !matches!(self.status, OrderStatus::Confirmed | OrderStatus::Shipped) {
+            return Err(OrderError::InvalidState("Can only fill confirmed or shipped orders"));
+        }
+
+        let item_index = self.items.iter()
+            .position(|i| i.product_id() == product_id)
+            .ok_or(OrderError::ItemNotFound)?;
+
+        if executed > self.items[item_index].remaining_quantity() {
+            return Err(OrderError::OrderOverfill);
+        }
+
+        if !self.partially_fillable && final_execution {
+            let ordered: u32 = self.items.iter().map(|i| i.quantity()).sum();
+            let executed_so_far: u32 = self.items.iter().map(|i| i.executed_quantity()).sum();
+            if executed_so_far + executed < ordered {
+                return Err(OrderError::PartialFillNotAllowed);
+            }
+        }
+
+        self.items[item_index].record_execution(executed);
+
+        self.events.push(OrderEvent::Filled {
+            order_id: self.id.clone(),
+            product_id: product_id.clone(),
+            executed,
+            executed_price,
+        });
+
+        if self.fulfillment_status() == FulfillmentStatus::FullyFilled {
+            self.status = OrderStatus::Fulfilled;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the order's fulfillment progress, derived from the executed
+    /// quantity of each line item.
+    pub fn fulfillment_status(&self) -> FulfillmentStatus {
+        let ordered: u32 = self.items.iter().map(|i| i.quantity()).sum();
+        let executed: u32 = self.items.iter().map(|i| i.executed_quantity()).sum();
+
+        if executed == 0 {
+            FulfillmentStatus::Unfilled
+        } else if executed >= ordered {
+            FulfillmentStatus::FullyFilled
+        } else {
+            FulfillmentStatus::PartiallyFilled
+        }
+    }
+
+    /// Cancels the order for the given reason.
+    ///
+    /// This is the only way to move an order into the terminal `Cancelled`
+    /// state; callers such as the expiry reaper are responsible for skipping
+    /// orders that are already cancelled so this call stays idempotent from
+    /// their perspective.
+    ///
+    /// # Errors
+    ///
+    /// * [`OrderError::InvalidState`] - If the order is not `Draft` or `Confirmed`.
+    pub fn cancel(&mut self, reason: OrderReason) -> Result<(), OrderError> {
+        if !matches!(self.status, OrderStatus::Draft | OrderStatus::Confirmed) {
+            return Err(OrderError::InvalidState("Can only cancel draft or confirmed orders"));
+        }
+
+        self.status = OrderStatus::Cancelled;
+        self.events.push(OrderEvent::Cancelled {
+            order_id: self.id.clone(),
+            reason,
+        });
+        Ok(())
+    }
+
     /// Adds a product to the order or increases quantity if already present.
     ///
     /// This method implements idempotent item addition - adding the same
@@ -99,7 +303,12 @@ impl Order {
     ///
     /// * `product_id` - The identifier of the product to add.
     /// * `quantity` - Number of units to order (must be positive).
-    /// * `unit_price` - The current price per unit.
+    /// * `unit_price` - The price per unit this item's subtotal is computed
+    ///   at (executions report their own price to [`Order::fill_item`]): the live
+    ///   product price, for both market orders and limit orders that have
+    ///   already cleared validation against the customer's limit (see
+    ///   [`OrderKind::Limit`]).
+    /// * `kind` - Whether this is a market or limit order.
     ///
     /// # Errors
     ///
@@ -110,6 +319,7 @@ impl Order {
         product_id: ProductId,
         quantity: u32,
         unit_price: Money,
+        kind: OrderKind,
     ) -> Result<(), OrderError> {
         if self.status == OrderStatus::Cancelled {
             return Err(OrderError::CannotModifyCancelled);
@@ -126,7 +336,7 @@ impl Order {
             return Ok(());
         }
 
-        self.items.push(OrderItem::new(product_id, quantity, unit_price));
+        self.items.push(OrderItem::new(product_id, quantity, unit_price, kind));
         Ok(())
     }
 
@@ -136,11 +346,18 @@ impl Order {
     /// occurs after payment processing. Only draft orders with at least one
     /// item can be confirmed.
     ///
+    /// # Arguments
+    ///
+    /// * `rates` - Exchange rates, keyed by source currency code, needed to
+    ///   convert any line item not already priced in [`Order::settlement_currency`].
+    ///   Resolved by the caller via an `ExchangeRateProvider` before calling.
+    ///
     /// # Errors
     ///
     /// * [`OrderError::InvalidState`] - If order is not in Draft status.
     /// * [`OrderError::EmptyOrder`] - If order has no items.
-    pub fn confirm(&mut self) -> Result<(), OrderError> {
+    /// * [`OrderError::Money`] - If a line item's currency has no available rate.
+    pub fn confirm(&mut self, rates: &HashMap<String, Rate>) -> Result<(), OrderError> {
         if self.status != OrderStatus::Draft {
             return Err(OrderError::InvalidState("Can only confirm draft orders"));
         }
@@ -148,23 +365,50 @@ impl Order {
             return Err(OrderError::EmptyOrder);
         }
 
+        let total = self.total(rates)?;
+
         self.status = OrderStatus::Confirmed;
         self.events.push(OrderEvent::Confirmed {
             order_id: self.id.clone(),
-            total: self.total(),
+            total,
         });
         Ok(())
     }
 
-    /// Calculates the sum of all line item subtotals.
+    /// Calculates the sum of all line item subtotals, converted into
+    /// [`Order::settlement_currency`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rates` - Exchange rates, keyed by source currency code, used to
+    ///   convert any line item not already priced in the settlement currency.
     ///
     /// # Returns
     ///
-    /// The total order amount in USD.
-    pub fn total(&self) -> Money {
-        self.items.iter().fold(Money::zero("USD"), |acc, item| {
-            acc.add(&item.subtotal()).unwrap_or(acc)
-        })
+    /// The total order amount in the settlement currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ConversionUnavailable`] (via [`OrderError::Money`])
+    /// if a line item's currency has no entry in `rates`.
+    pub fn total(&self, rates: &HashMap<String, Rate>) -> Result<Money, OrderError> {
+        let mut total = Money::zero(&self.settlement_currency);
+
+        for item in &self.items {
+            let subtotal = item.subtotal();
+
+            let converted = if subtotal.currency() == self.settlement_currency {
+                subtotal
+            } else {
+                let rate = rates.get(subtotal.currency())
+                    .ok_or(MoneyError::ConversionUnavailable)?;
+                subtotal.convert(&self.settlement_currency, rate)?
+            };
+
+            total = total.add(&converted)?;
+        }
+
+        Ok(total)
     }
 
     /// Returns the current lifecycle state of the order.
@@ -206,4 +450,21 @@ pub enum OrderError {
     /// Operation not allowed in current order state.
     #[error("Invalid state: {0}")]
     InvalidState(&'static str),
+
+    /// No line item matches the given product.
+    #[error("Order has no line item for this product")]
+    ItemNotFound,
+
+    /// Attempted to execute more than a line item's remaining quantity.
+    #[error("Execution exceeds ordered quantity")]
+    OrderOverfill,
+
+    /// Order is not partially fillable, but this execution would leave it
+    /// in a partially filled state.
+    #[error("Order does not allow partial fills")]
+    PartialFillNotAllowed,
+
+    /// A monetary operation failed, such as a missing conversion rate.
+    #[error("Money error: {0}")]
+    Money(#[from] MoneyError),
 }
@@ -6,6 +6,7 @@
 
 // crates/domain/src/order/repository.rs
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use super::aggregate::Order;
 use super::value_objects::OrderId;
 
@@ -74,6 +75,32 @@ pub trait OrderRepository: Send + Sync {
     ///
     /// Returns [`RepositoryError::Database`] if the delete operation fails.
     async fn delete(&self, order: &Order) -> Result<(), RepositoryError>;
+
+    /// Retrieves confirmed-but-unshipped orders whose expiry has passed.
+    ///
+    /// Used by the expiry reaper to find orders that the system should
+    /// cancel on the customer's behalf. Orders that are still in `Draft`,
+    /// already `Shipped`, or already `Cancelled` must not be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The instant to evaluate expiry against.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::Database`] if the query fails.
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<Order>, RepositoryError>;
+
+    /// Retrieves the candidate orders for fulfillment/settlement read
+    /// services, such as the application layer's solvable orders query.
+    ///
+    /// Implementations typically scope this to orders that are `Confirmed`
+    /// or further along, since `Draft` orders aren't yet actionable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepositoryError::Database`] if the query fails.
+    async fn find_candidates(&self) -> Result<Vec<Order>, RepositoryError>;
 }
 
 /// Errors that can occur during repository operations.
@@ -89,7 +89,7 @@ pub enum OrderIdError {
 /// let tax = Money::new(240, "USD")?;     // $2.40
 /// let total = price.add(&tax)?;          // $32.39
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Money {
     /// Amount in smallest currency unit (e.g., cents).
     amount: i64,
@@ -193,6 +193,37 @@ impl Money {
 
     /// Returns the ISO 4217 currency code.
     pub fn currency(&self) -> &str { &self.currency }
+
+    /// Converts this amount into another currency using the given exchange
+    /// [`Rate`].
+    ///
+    /// Uses integer arithmetic throughout, rounding the result to the
+    /// nearest smallest currency unit (round-half-up) rather than silently
+    /// truncating, to avoid eroding value through repeated conversions.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - ISO 4217 currency code to convert into.
+    /// * `rate` - The exchange rate to apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let amount = Money::new(1000, "USD")?;
+    /// let rate = Rate::new(85, 100)?; // 1 USD = 0.85 EUR
+    /// let converted = amount.convert("EUR", &rate)?;
+    /// assert_eq!(converted.amount(), 850);
+    /// ```
+    pub fn convert(&self, to: &str, rate: &Rate) -> Result<Self, MoneyError> {
+        let numerator = self.amount as i128 * rate.numerator as i128;
+        let denominator = rate.denominator as i128;
+        let rounded = (numerator + denominator / 2) / denominator;
+
+        Ok(Self {
+            amount: rounded as i64,
+            currency: to.to_uppercase(),
+        })
+    }
 }
 
 /// Errors that can occur when working with [`Money`].
@@ -205,4 +236,193 @@ pub enum MoneyError {
     /// Attempted to perform an operation with mismatched currencies.
     #[error("Currency mismatch")]
     CurrencyMismatch,
+
+    /// No exchange rate was available to perform a required conversion.
+    #[error("No exchange rate available for conversion")]
+    ConversionUnavailable,
+
+    /// Attempted to create a [`Rate`] with a non-positive denominator or a
+    /// negative numerator.
+    #[error("Invalid exchange rate")]
+    InvalidRate,
+}
+
+/// An exchange rate between two currencies, expressed as an exact fraction
+/// so conversions can be done with integer arithmetic instead of floats.
+///
+/// # Example
+///
+/// ```rust
+/// use domain::order::Rate;
+///
+/// // 1 unit of the source currency = 0.85 units of the target currency.
+/// let rate = Rate::new(85, 100)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rate {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rate {
+    /// Creates a new exchange rate equal to `numerator / denominator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if `denominator` is not positive
+    /// or `numerator` is negative.
+    pub fn new(numerator: i64, denominator: i64) -> Result<Self, MoneyError> {
+        if denominator <= 0 || numerator < 0 {
+            return Err(MoneyError::InvalidRate);
+        }
+        Ok(Self { numerator, denominator })
+    }
+}
+
+/// Why an [`Order`](super::aggregate::Order) was created or cancelled.
+///
+/// Trading systems generate orders and cancellations for reasons beyond a
+/// direct customer action; this value object records which one applied so
+/// downstream contexts (reporting, risk) can distinguish customer intent
+/// from system-driven behavior.
+///
+/// # Example
+///
+/// ```rust
+/// use domain::order::OrderReason;
+///
+/// let reason = OrderReason::Manual;
+/// assert_eq!(reason, OrderReason::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderReason {
+    /// The order was placed or cancelled directly by a customer.
+    #[default]
+    Manual,
+    /// The order was cancelled by the system because its expiry passed.
+    Expired,
+    /// The order was cancelled as part of a liquidation.
+    Liquidation,
+    /// The order was created to realize a take-profit target.
+    TakeProfit,
+    /// The order was created to realize a stop-loss target.
+    StopLoss,
+}
+
+/// Which side of a trade an order is on.
+///
+/// A limit price is only a ceiling for a buy order and only a floor for a
+/// sell order; validating it without knowing the side checks the wrong
+/// direction half the time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    /// The customer is buying; the limit price is the maximum acceptable price.
+    Buy,
+    /// The customer is selling; the limit price is the minimum acceptable price.
+    Sell,
+}
+
+/// Whether a line item's price is determined at fulfillment time or
+/// constrained by the customer up front.
+///
+/// Conflating the two in a single concrete `unit_price` forces market
+/// orders to carry a price that hasn't actually been agreed to yet; this
+/// value object keeps the distinction explicit.
+///
+/// # Example
+///
+/// ```rust
+/// use domain::order::{OrderKind, OrderSide, Money};
+///
+/// let market = OrderKind::Market;
+/// let limit = OrderKind::Limit {
+///     side: OrderSide::Buy,
+///     limit_price: Money::new(2999, "USD")?,
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderKind {
+    /// Priced at the live product price when the item is added to the
+    /// order; the customer sets no limit.
+    Market,
+    /// Customer specifies the max (buy) or min (sell) acceptable unit price.
+    Limit {
+        /// Which side of the trade this limit applies to.
+        side: OrderSide,
+        /// The customer's acceptable price limit per unit.
+        limit_price: Money,
+    },
+}
+
+/// A single line item within an [`Order`](super::aggregate::Order).
+///
+/// # Example
+///
+/// ```rust
+/// use domain::order::{OrderItem, OrderKind, ProductId, Money};
+///
+/// let product_id = ProductId::from_string("prod-456")?;
+/// let price = Money::new(2999, "USD")?;
+/// let item = OrderItem::new(product_id, 2, price, OrderKind::Market);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderItem {
+    product_id: ProductId,
+    quantity: u32,
+    unit_price: Money,
+    kind: OrderKind,
+    executed_quantity: u32,
+}
+
+impl OrderItem {
+    /// Creates a new line item.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_id` - The identifier of the ordered product.
+    /// * `quantity` - The number of units ordered.
+    /// * `unit_price` - The price per unit this item was quoted at.
+    /// * `kind` - Whether this is a market or limit order.
+    pub fn new(product_id: ProductId, quantity: u32, unit_price: Money, kind: OrderKind) -> Self {
+        Self { product_id, quantity, unit_price, kind, executed_quantity: 0 }
+    }
+
+    /// Increases the ordered quantity by `additional` units.
+    pub fn increase_quantity(&mut self, additional: u32) {
+        self.quantity += additional;
+    }
+
+    /// Calculates this item's contribution to the order total.
+    pub fn subtotal(&self) -> Money {
+        self.unit_price.multiply(self.quantity as i32)
+    }
+
+    /// Returns the identifier of the ordered product.
+    pub fn product_id(&self) -> &ProductId { &self.product_id }
+
+    /// Returns the number of units ordered.
+    pub fn quantity(&self) -> u32 { self.quantity }
+
+    /// Returns the price per unit this item was quoted at.
+    pub fn unit_price(&self) -> &Money { &self.unit_price }
+
+    /// Returns whether this is a market or limit order.
+    pub fn kind(&self) -> &OrderKind { &self.kind }
+
+    /// Returns the quantity of this item executed so far.
+    pub fn executed_quantity(&self) -> u32 { self.executed_quantity }
+
+    /// Returns the quantity of this item still awaiting execution.
+    pub fn remaining_quantity(&self) -> u32 {
+        self.quantity - self.executed_quantity
+    }
+
+    /// Records an incremental execution against this item.
+    ///
+    /// Callers must check [`OrderItem::remaining_quantity`] before calling
+    /// this; it does not itself guard against overfills so that the
+    /// aggregate can raise its own domain-specific error.
+    pub fn record_execution(&mut self, executed: u32) {
+        self.executed_quantity += executed;
+    }
 }